@@ -0,0 +1,456 @@
+use std::fs::{self, Permissions, Metadata};
+use std::fmt::{Result as FmtResult, Formatter, Display};
+use time::{Duration, OffsetDateTime, UtcOffset};
+use std::os::unix::prelude::{PermissionsExt, MetadataExt, FileTypeExt};
+use std::time::SystemTime;
+use std::path::Path;
+use users::{get_user_by_uid, get_group_by_gid};
+use crate::parser::{SortBy, SizeFormat};
+
+mod archive;
+
+
+
+/// Entry point for a single CLI argument: prints a single box for a file or
+/// symlink, or one box per entry when the argument is a directory or a tar
+/// archive.
+pub(crate) fn inspect(arg: &String, sort_by: SortBy, force_archive: bool, size_format: SizeFormat, report_link: bool) {
+    let path = Path::new(arg.as_str());
+
+    if force_archive || archive::looks_like_archive(path) {
+        archive::list_archive(path, size_format);
+    } else if report_link && path.is_symlink() {
+        // Checked before `is_dir()`, which follows symlinks: otherwise
+        // `--link` on a symlink-to-directory would list the target instead
+        // of reporting on the link itself.
+        println!("{}", File::new_link(arg, size_format));
+    } else if path.is_dir() {
+        list_directory(path, sort_by, size_format, report_link);
+    } else if report_link {
+        println!("{}", File::new_link(arg, size_format));
+    } else {
+        println!("{}", File::new(arg, size_format));
+    }
+}
+
+fn list_directory(path: &Path, sort_by: SortBy, size_format: SizeFormat, report_link: bool) {
+    let read_dir = fs::read_dir(path)
+        .expect("failed to read directory {path:#?}");
+
+    let mut entries = read_dir
+        .filter_map(|entry| entry.ok())
+        .map(|entry| {
+            let entry_path = entry.path().display().to_string();
+            if report_link && Path::new(&entry_path).is_symlink() {
+                File::new_link(&entry_path, size_format)
+            } else {
+                File::new(&entry_path, size_format)
+            }
+        })
+        .collect::<Vec<_>>();
+
+    match sort_by {
+        SortBy::Kind => entries.sort_by(|a, b| a.kind_sort_key().cmp(&b.kind_sort_key())),
+        SortBy::Name => entries.sort_by(|a, b| a.name.cmp(&b.name)),
+        SortBy::Date => entries.sort_by_key(|file| file.date.modified_raw),
+        SortBy::Size => entries.sort_by_key(|file| file.size_bytes),
+        SortBy::Extension => entries.sort_by(|a, b| a.extension().cmp(&b.extension())),
+    }
+
+    entries.iter().for_each(|file| println!("{}", file));
+}
+
+
+
+#[derive(Debug, Clone)]
+pub(crate) struct File {
+    name: String,
+    size: String,
+    size_bytes: u64,
+    permissions: FilePermissions,
+    owner: String,
+    group: String,
+    kind: FileKind,
+    date: FileDate,
+}
+
+impl File {
+    pub(crate) fn new(arg: &String, size_format: SizeFormat) -> Self {
+        Self::build(arg, false, size_format)
+    }
+
+    /// Like [`Self::new`], but for a symlink argument reports the link's own
+    /// attributes (via `symlink_metadata`) instead of following it to the target.
+    pub(crate) fn new_link(arg: &String, size_format: SizeFormat) -> Self {
+        Self::build(arg, true, size_format)
+    }
+
+    fn build(arg: &String, report_link: bool, size_format: SizeFormat) -> Self {
+        let path = Path::new(arg.as_str());
+        let link_metadata = fs::symlink_metadata(path)
+            .expect("failed to get metadata from file {path:#?}");
+        let is_symlink = link_metadata.file_type().is_symlink();
+
+        let kind = if is_symlink {
+            let target = fs::read_link(path).ok().map(|target| target.display().to_string());
+            let dangling = !path.exists();
+            FileKind::Symlink { target, dangling }
+        } else {
+            FileKind::new(&link_metadata)
+        };
+
+        // `fs::metadata` is a plain `stat`, not an `open` — unlike
+        // `RawFile::open(...).metadata()`, it can't block forever on a
+        // symlink that resolves to a FIFO/socket with no reader or writer.
+        let metadata = if is_symlink && !report_link {
+            fs::metadata(path).unwrap_or_else(|_| link_metadata.clone())
+        } else {
+            link_metadata
+        };
+
+        let name = path
+            .file_name()
+            .map_or_else(|| "unknown".to_owned(), |val| val.to_str().unwrap().to_owned());
+        let size_bytes = metadata.len();
+        let size = Self::determine_size(size_bytes, size_format);
+        let permissions = FilePermissions::new(metadata.permissions());
+        let owner = Self::resolve_owner(metadata.uid());
+        let group = Self::resolve_group(metadata.gid());
+        let date = FileDate::from(metadata);
+
+        Self { name, size, size_bytes, permissions, owner, group, kind, date }
+    }
+
+    /// Sort key for [`SortBy::Kind`]: a group prefix (directories, then regular
+    /// files, then symlinks, then device/socket/fifo) followed by the filename,
+    /// so entries sort by class first and alphabetically within each class.
+    fn kind_sort_key(&self) -> String {
+        format!("{}{}", self.kind.sort_rank(), self.name)
+    }
+
+    fn extension(&self) -> String {
+        Path::new(&self.name)
+            .extension()
+            .map_or_else(String::new, |ext| ext.to_string_lossy().into_owned())
+    }
+
+    fn resolve_owner(uid: u32) -> String {
+        get_user_by_uid(uid)
+            .map_or_else(|| uid.to_string(), |user| user.name().to_string_lossy().into_owned())
+    }
+
+    fn resolve_group(gid: u32) -> String {
+        get_group_by_gid(gid)
+            .map_or_else(|| gid.to_string(), |group| group.name().to_string_lossy().into_owned())
+    }
+
+    /// Renders a byte count in the requested [`SizeFormat`], walking the unit
+    /// ladder for that base until the value fits, rounded to one decimal place
+    /// so the output stays aligned in the fixed-width box.
+    fn determine_size(value: u64, format: SizeFormat) -> String {
+        if format == SizeFormat::Bytes {
+            return format!("{value} bytes");
+        }
+
+        let (base, units): (f64, &[&str]) = match format {
+            SizeFormat::Si => (1000., &["bytes", "kB", "MB", "GB", "TB", "PB"]),
+            SizeFormat::Iec => (1024., &["bytes", "KiB", "MiB", "GiB", "TiB", "PiB"]),
+            SizeFormat::Bytes => unreachable!(),
+        };
+
+        let mut size = value as f64;
+        let mut unit = 0;
+
+        while size >= base && unit < units.len() - 1 {
+            size /= base;
+            unit += 1;
+        }
+
+        // Rounding to one decimal place can push the displayed value up to
+        // the next unit's boundary (e.g. 1023.95 KiB -> "1024.0 KiB");
+        // promote once more when that happens.
+        let mut rounded = (size * 10.).round() / 10.;
+        if rounded >= base && unit < units.len() - 1 {
+            unit += 1;
+            rounded /= base;
+        }
+
+        if unit == 0 {
+            format!("{value} bytes")
+        } else {
+            format!("{rounded:.1} {}", units[unit])
+        }
+    }
+}
+
+impl Display for File {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let name_line = format!("│ {:^63.63} │", self.name);
+        let size_line = format!("│ Size: {:<57.57} │", self.size);
+        let permissions_line = format!("│ Permissions: {:<50.50} │", self.permissions.to_string());
+        let owner_line = format!("│ Owner: {:<56.56} │", self.owner);
+        let group_line = format!("│ Group: {:<56.56} │", self.group);
+        // `.57` guards against FileKind::Symlink's unbounded `read_link` target
+        // (chunk0-4) blowing past the fixed-width box, same as the name field.
+        let kind_line = format!("│ Type: {:<57.57} │", self.kind.to_string());
+        let created_line = format!("│ Created: {:<54.54} │", self.date.created);
+        let modified_line = format!("│ Modified: {:<53.53} │", self.date.modified);
+        let accessed_line = format!("│ Accessed: {:<53.53} │", self.date.accessed);
+
+
+        let horizontal_line = "─".repeat(65);
+
+        write!(f, "╭{}╮\n", horizontal_line)?;
+        write!(f, "{}\n", name_line)?;
+        write!(f, "├{}┤\n", horizontal_line)?;
+
+        write!(f, "{}\n", size_line)?;
+        write!(f, "{}\n", permissions_line)?;
+        write!(f, "{}\n", owner_line)?;
+        write!(f, "{}\n", group_line)?;
+        write!(f, "{}\n", kind_line)?;
+        write!(f, "{}\n", created_line)?;
+        write!(f, "{}\n", modified_line)?;
+        write!(f, "{}\n", accessed_line)?;
+        write!(f, "╰{}╯", horizontal_line)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct PermSet {
+    read: bool,
+    write: bool,
+    execute: bool,
+}
+
+impl PermSet {
+    fn symbolic(&self, set_char: char, unset_exec_char: char, special: bool) -> String {
+        let read = if self.read { 'r' } else { '-' };
+        let write = if self.write { 'w' } else { '-' };
+        let execute = match (self.execute, special) {
+            (true, true) => set_char,
+            (false, true) => unset_exec_char,
+            (true, false) => 'x',
+            (false, false) => '-',
+        };
+
+        format!("{read}{write}{execute}")
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct FilePermissions {
+    owner: PermSet,
+    group: PermSet,
+    other: PermSet,
+    setuid: bool,
+    setgid: bool,
+    sticky: bool,
+    mode: u32,
+}
+
+impl FilePermissions {
+    pub(crate) fn new(value: Permissions) -> Self {
+        let mode = value.mode();
+
+        let owner = PermSet {
+            read: mode & 0o400 != 0,
+            write: mode & 0o200 != 0,
+            execute: mode & 0o100 != 0,
+        };
+        let group = PermSet {
+            read: mode & 0o040 != 0,
+            write: mode & 0o020 != 0,
+            execute: mode & 0o010 != 0,
+        };
+        let other = PermSet {
+            read: mode & 0o004 != 0,
+            write: mode & 0o002 != 0,
+            execute: mode & 0o001 != 0,
+        };
+
+        let setuid = mode & 0o4000 != 0;
+        let setgid = mode & 0o2000 != 0;
+        let sticky = mode & 0o1000 != 0;
+
+        Self { owner, group, other, setuid, setgid, sticky, mode }
+    }
+
+    /// `ls -l`-style symbolic rendering, e.g. `rwxr-xr--`.
+    pub(crate) fn symbolic(&self) -> String {
+        format!(
+            "{}{}{}",
+            self.owner.symbolic('s', 'S', self.setuid),
+            self.group.symbolic('s', 'S', self.setgid),
+            self.other.symbolic('t', 'T', self.sticky),
+        )
+    }
+
+    /// Octal rendering, e.g. `0754`.
+    pub(crate) fn octal(&self) -> String {
+        format!("{:04o}", self.mode & 0o7777)
+    }
+}
+
+impl Display for FilePermissions {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{} ({})", self.symbolic(), self.octal())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(crate) enum FileKind {
+    Regular,
+    Folder,
+    /// Carries the link's target (when resolvable) and whether it's dangling.
+    /// Built directly by [`File::build`], which has the path the metadata lacks.
+    Symlink { target: Option<String>, dangling: bool },
+    BlockDevice { major: u32, minor: u32 },
+    CharDevice { major: u32, minor: u32 },
+    Socket,
+    Fifo,
+}
+
+impl FileKind {
+    fn new(metadata: &Metadata) -> Self {
+        let file_type = metadata.file_type();
+
+        if file_type.is_file() {
+            Self::Regular
+        } else if file_type.is_dir() {
+            Self::Folder
+        } else if file_type.is_block_device() {
+            let rdev = metadata.rdev();
+            Self::BlockDevice { major: device_major(rdev), minor: device_minor(rdev) }
+        } else if file_type.is_char_device() {
+            let rdev = metadata.rdev();
+            Self::CharDevice { major: device_major(rdev), minor: device_minor(rdev) }
+        } else if file_type.is_socket() {
+            Self::Socket
+        } else if file_type.is_fifo() {
+            Self::Fifo
+        } else {
+            unreachable!("is your file from other universe? you've reached unreachable!")
+        }
+    }
+
+    /// Sort-class rank used by [`SortBy::Kind`]: folders, then regular files,
+    /// then symlinks, then device/socket/fifo special files.
+    fn sort_rank(&self) -> char {
+        match self {
+            Self::Folder => 'a',
+            Self::Regular => 'b',
+            Self::Symlink { .. } => 'c',
+            Self::BlockDevice { .. } | Self::CharDevice { .. } | Self::Socket | Self::Fifo => 'd',
+        }
+    }
+}
+
+/// Extracts the major device number from a raw `st_rdev`, glibc-style.
+fn device_major(rdev: u64) -> u32 {
+    (((rdev >> 8) & 0xfff) | ((rdev >> 32) & !0xfff)) as u32
+}
+
+/// Extracts the minor device number from a raw `st_rdev`, glibc-style.
+fn device_minor(rdev: u64) -> u32 {
+    ((rdev & 0xff) | ((rdev >> 12) & !0xff)) as u32
+}
+
+impl Display for FileKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Regular => write!(f, "Regular"),
+            Self::Folder => write!(f, "Folder"),
+            Self::Symlink { target: Some(target), dangling: true } => write!(f, "Symlink → {target} (dangling)"),
+            Self::Symlink { target: Some(target), dangling: false } => write!(f, "Symlink → {target}"),
+            Self::Symlink { target: None, .. } => write!(f, "Symlink"),
+            Self::BlockDevice { major, minor } => write!(f, "Block Device ({major}, {minor})"),
+            Self::CharDevice { major, minor } => write!(f, "Character Device ({major}, {minor})"),
+            Self::Socket => write!(f, "Socket"),
+            Self::Fifo => write!(f, "FIFO"),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct FileDate {
+    created: String,
+    modified: String,
+    accessed: String,
+    modified_raw: SystemTime,
+}
+
+impl FileDate {
+    fn parse_time(systime: SystemTime, epoch: OffsetDateTime) -> String {
+        let utc = epoch + Duration::try_from(systime.duration_since(epoch.into()).unwrap())
+            .unwrap();
+        let local = utc.to_offset(UtcOffset::local_offset_at(utc).unwrap());
+
+        let date = local.date();
+        let time = local.time();
+
+        format!("{} {}", date, time)
+    }
+
+    /// Builds a [`FileDate`] from a raw `mtime` (seconds since the Unix epoch),
+    /// the only timestamp tar headers carry — used for archive entries, which
+    /// have no separate created/accessed times, so all three fields show the mtime.
+    fn from_epoch_seconds(value: u64) -> Self {
+        let epoch = OffsetDateTime::UNIX_EPOCH;
+        let modified_raw = SystemTime::UNIX_EPOCH + std::time::Duration::from_secs(value);
+        let rendered = Self::parse_time(modified_raw, epoch);
+
+        Self {
+            created: rendered.clone(),
+            modified: rendered.clone(),
+            accessed: rendered,
+            modified_raw,
+        }
+    }
+}
+
+impl From<Metadata> for FileDate {
+    fn from(value: Metadata) -> Self {
+        let epoch = OffsetDateTime::UNIX_EPOCH;
+
+        let created = value
+            .created()
+            .map_or_else(|_| "unknown".to_string(), |val| Self::parse_time(val, epoch));
+        let modified = value
+            .modified()
+            .map_or_else(|_| "unknown".to_string(), |val| Self::parse_time(val, epoch));
+        let accessed = value
+            .accessed()
+            .map_or_else(|_| "unknown".to_string(), |val| Self::parse_time(val, epoch));
+        let modified_raw = value.modified().unwrap_or(SystemTime::UNIX_EPOCH);
+
+        Self { created, modified, accessed, modified_raw }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn determine_size_stays_under_one_decimal_in_bytes() {
+        assert_eq!(File::determine_size(512, SizeFormat::Iec), "512 bytes");
+    }
+
+    #[test]
+    fn determine_size_promotes_past_a_rounded_up_boundary() {
+        // 1_048_550 / 1024 rounds to 1024.0 KiB before promotion; it should
+        // be reported as 1.0 MiB instead of sitting at the KiB boundary.
+        assert_eq!(File::determine_size(1_048_550, SizeFormat::Iec), "1.0 MiB");
+    }
+
+    #[test]
+    fn determine_size_respects_si_base() {
+        assert_eq!(File::determine_size(1_500_000, SizeFormat::Si), "1.5 MB");
+    }
+
+    #[test]
+    fn determine_size_bytes_mode_is_exact() {
+        assert_eq!(File::determine_size(2048, SizeFormat::Bytes), "2048 bytes");
+    }
+}
@@ -5,13 +5,13 @@ mod informer;
 mod parser;
 
 use crate::parser::get_args;
-use crate::informer::File;
+use crate::informer::inspect;
 
 
 
 fn main() {
     let args = get_args();
-    args
+    args.paths
         .iter()
-        .for_each(|arg| println!("{}", File::new(arg)));
+        .for_each(|path| inspect(path, args.sort_by, args.force_archive, args.size_format, args.report_link));
 }
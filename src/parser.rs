@@ -0,0 +1,100 @@
+use std::env;
+
+
+
+#[derive(Debug, Clone)]
+pub(crate) struct Args {
+    pub(crate) paths: Vec<String>,
+    pub(crate) sort_by: SortBy,
+    /// Forces archive-inspection mode even when the path doesn't look like a
+    /// `.tar`/`.tar.gz` by extension or magic bytes.
+    pub(crate) force_archive: bool,
+    pub(crate) size_format: SizeFormat,
+    /// Reports on a symlink argument itself (via `symlink_metadata`) instead
+    /// of following it to the target.
+    pub(crate) report_link: bool,
+}
+
+pub(crate) fn get_args() -> Args {
+    let mut paths = Vec::new();
+    let mut sort_by = SortBy::default();
+    let mut force_archive = false;
+    let mut size_format = SizeFormat::default();
+    let mut report_link = false;
+
+    let mut raw = env::args().skip(1);
+    while let Some(arg) = raw.next() {
+        match arg.as_str() {
+            "--sort" => {
+                if let Some(value) = raw.next() {
+                    sort_by = SortBy::parse(&value).unwrap_or(sort_by);
+                }
+            },
+            "--archive" => force_archive = true,
+            "--size-unit" => {
+                if let Some(value) = raw.next() {
+                    size_format = SizeFormat::parse(&value).unwrap_or(size_format);
+                }
+            },
+            "--bytes" => size_format = SizeFormat::Bytes,
+            "--link" | "-l" => report_link = true,
+            _ => paths.push(arg),
+        }
+    }
+
+    Args { paths, sort_by, force_archive, size_format, report_link }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum SortBy {
+    Kind,
+    Name,
+    Date,
+    Size,
+    Extension,
+}
+
+impl SortBy {
+    fn parse(value: &str) -> Option<Self> {
+        match value {
+            "kind" => Some(Self::Kind),
+            "name" => Some(Self::Name),
+            "date" => Some(Self::Date),
+            "size" => Some(Self::Size),
+            "extension" | "ext" => Some(Self::Extension),
+            _ => None,
+        }
+    }
+}
+
+impl Default for SortBy {
+    fn default() -> Self {
+        Self::Kind
+    }
+}
+
+/// Human-readable size rendering: IEC binary units (`KiB`, base 1024), SI
+/// decimal units (`kB`, base 1000), or the exact byte count.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum SizeFormat {
+    Iec,
+    Si,
+    Bytes,
+}
+
+impl SizeFormat {
+    fn parse(value: &str) -> Option<Self> {
+        match value {
+            "iec" => Some(Self::Iec),
+            "si" => Some(Self::Si),
+            "bytes" => Some(Self::Bytes),
+            _ => None,
+        }
+    }
+}
+
+impl Default for SizeFormat {
+    fn default() -> Self {
+        Self::Iec
+    }
+}